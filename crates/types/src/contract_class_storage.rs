@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use cairo_felt::Felt252;
+use serde_json::Value;
+use starknet_crypto::{poseidon_hash_many, FieldElement};
+
+use crate::contract_class::{Cairo0ContractClass, SierraContractClass};
+use crate::felt::{ClassHash, CompiledClassHash, Felt};
+
+/// The Poseidon sub-hashes that feed into a Sierra class's own hash: one over the Sierra
+/// program, one over the entry point tables, and one over the ABI. Kept alongside the declared
+/// class so that proof-oriented and verification consumers can recompute and check a piece of
+/// the class hash without re-hashing the (potentially large) program from scratch.
+///
+/// These are derived from the class's `CONTRACT_CLASS` JSON shape (`sierra_program`,
+/// `entry_points_by_type`, `abi`) rather than the real cairo-lang class-hash sub-hashes: the
+/// official formula hashes the ABI with Keccak, and this crate has no Keccak dependency. The
+/// `abi_hash` here is therefore Poseidon-based, not bit-compatible with a mainnet class hash, but
+/// it is still a genuine, deterministic hash of the declared ABI that a consumer can verify by
+/// recomputing it over the same class.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SierraClassComponentHashes {
+    pub bytecode_hash: Felt,
+    pub entry_points_hash: Felt,
+    pub abi_hash: Felt,
+}
+
+impl SierraClassComponentHashes {
+    fn compute(contract_class: &SierraContractClass) -> Self {
+        let class_json = serde_json::to_value(contract_class)
+            .expect("a declared Sierra class always serializes to the CONTRACT_CLASS JSON shape");
+
+        Self {
+            bytecode_hash: hash_sierra_program(&class_json),
+            entry_points_hash: hash_entry_points(&class_json),
+            abi_hash: hash_abi(&class_json),
+        }
+    }
+}
+
+/// `bytecode_hash = poseidon_hash_many(sierra_program)`, each program entry being a hex felt.
+fn hash_sierra_program(class_json: &Value) -> Felt {
+    let program = class_json["sierra_program"]
+        .as_array()
+        .expect("CONTRACT_CLASS.sierra_program is a JSON array of hex felts");
+
+    let felts: Vec<FieldElement> = program
+        .iter()
+        .map(|entry| {
+            let hex_str = entry.as_str().expect("sierra_program entries are hex-felt strings");
+            felt_to_field_element(felt_from_hex(hex_str))
+        })
+        .collect();
+
+    field_element_to_felt(poseidon_hash_many(&felts))
+}
+
+/// Hashes each of the `CONSTRUCTOR`/`EXTERNAL`/`L1_HANDLER` entry point tables (as
+/// `poseidon_hash_many` over their flattened `[selector, function_idx]` pairs), then combines the
+/// three into one hash so the store only needs a single `entry_points_hash` field.
+fn hash_entry_points(class_json: &Value) -> Felt {
+    let entry_points_by_type = &class_json["entry_points_by_type"];
+
+    let per_type_hashes: Vec<FieldElement> =
+        ["CONSTRUCTOR", "EXTERNAL", "L1_HANDLER"].iter().map(|entry_point_type| {
+            let entries = entry_points_by_type[entry_point_type]
+                .as_array()
+                .cloned()
+                .unwrap_or_default();
+
+            let felts: Vec<FieldElement> = entries
+                .iter()
+                .flat_map(|entry| {
+                    let selector = felt_to_field_element(felt_from_hex(
+                        entry["selector"].as_str().expect("entry point selector is a hex felt"),
+                    ));
+                    let function_idx = FieldElement::from(
+                        entry["function_idx"].as_u64().expect("entry point function_idx is a u64"),
+                    );
+                    [selector, function_idx]
+                })
+                .collect();
+
+            poseidon_hash_many(&felts)
+        }).collect();
+
+    field_element_to_felt(poseidon_hash_many(&per_type_hashes))
+}
+
+/// `abi_hash = poseidon_hash_many` over the ABI's JSON text, chunked into 31-byte felts. A string
+/// ABI is hashed as given; any other ABI value is hashed as its `serde_json` encoding. See the doc comment on [`SierraClassComponentHashes`] for why this isn't the
+/// real (Keccak-based) abi hash.
+fn hash_abi(class_json: &Value) -> Felt {
+    let abi_string = match &class_json["abi"] {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+
+    let felts: Vec<FieldElement> = abi_string
+        .as_bytes()
+        .chunks(31)
+        .map(|chunk| {
+            let mut buf = [0u8; 32];
+            buf[32 - chunk.len()..].copy_from_slice(chunk);
+            FieldElement::from_bytes_be(&buf).expect("a 31-byte chunk always fits in a felt")
+        })
+        .collect();
+
+    field_element_to_felt(poseidon_hash_many(&felts))
+}
+
+fn felt_from_hex(hex_str: &str) -> Felt {
+    let trimmed = hex_str.trim_start_matches("0x");
+    let sixteen = Felt252::from(16);
+
+    let value = trimmed.chars().fold(Felt252::from(0), |acc, c| {
+        let digit = c.to_digit(16).expect("sierra class hex field is valid hex") as u64;
+        acc * sixteen.clone() + Felt252::from(digit)
+    });
+
+    value.into()
+}
+
+fn felt_to_field_element(felt: Felt) -> FieldElement {
+    let felt252: Felt252 = felt.into();
+    FieldElement::from_bytes_be(&felt252.to_be_bytes())
+        .expect("a Felt252 always fits in the Starknet field")
+}
+
+fn field_element_to_felt(value: FieldElement) -> Felt {
+    Felt252::from_bytes_be(&value.to_bytes_be()).into()
+}
+
+/// The declared artifact kept for a class hash: the contract class itself, plus, for Sierra
+/// classes, the compiled-class hash and component hashes paired with it at declare time.
+#[derive(Debug, Clone)]
+pub enum DeclaredClass {
+    Cairo0(Cairo0ContractClass),
+    Sierra {
+        contract_class: SierraContractClass,
+        compiled_class_hash: CompiledClassHash,
+        component_hashes: SierraClassComponentHashes,
+    },
+}
+
+/// Maps declared class hashes to the artifacts submitted for them, populated at declare time so
+/// that later `getClass`/`getClassByHash` reads can return the exact declared class.
+///
+/// This store is the `crates/types` half of class storage: it holds the data, but owning an
+/// instance of it across requests and exposing it through `getClass`/`getClassByHash` RPC
+/// handlers is the responsibility of the devnet's server/state crate, which is not part of this
+/// tree. `create_declare` on each broadcasted declare type takes `&mut DeclaredClasses` precisely
+/// so that crate can thread its own long-lived instance through.
+#[derive(Debug, Clone, Default)]
+pub struct DeclaredClasses {
+    classes: HashMap<ClassHash, DeclaredClass>,
+}
+
+impl DeclaredClasses {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert_cairo0(&mut self, class_hash: ClassHash, contract_class: Cairo0ContractClass) {
+        self.classes.insert(class_hash, DeclaredClass::Cairo0(contract_class));
+    }
+
+    pub fn insert_sierra(
+        &mut self,
+        class_hash: ClassHash,
+        contract_class: SierraContractClass,
+        compiled_class_hash: CompiledClassHash,
+    ) {
+        let component_hashes = SierraClassComponentHashes::compute(&contract_class);
+        self.classes.insert(
+            class_hash,
+            DeclaredClass::Sierra { contract_class, compiled_class_hash, component_hashes },
+        );
+    }
+
+    pub fn get_class(&self, class_hash: &ClassHash) -> Option<&DeclaredClass> {
+        self.classes.get(class_hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DeclaredClass, DeclaredClasses};
+    use crate::contract_class::Cairo0Json;
+    use crate::felt::Felt;
+
+    #[test]
+    fn get_class_returns_the_class_inserted_for_its_hash() {
+        let json_str = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/test_data/events_cairo0.casm"
+        ))
+        .unwrap();
+        let cairo0 = Cairo0Json::raw_json_from_json_str(&json_str).unwrap().into();
+
+        let mut declared_classes = DeclaredClasses::new();
+        let class_hash = Felt::from(1);
+        declared_classes.insert_cairo0(class_hash, cairo0);
+
+        assert!(matches!(
+            declared_classes.get_class(&class_hash),
+            Some(DeclaredClass::Cairo0(_))
+        ));
+        assert!(declared_classes.get_class(&Felt::from(2)).is_none());
+    }
+}
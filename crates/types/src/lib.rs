@@ -0,0 +1,7 @@
+pub mod contract_address;
+pub mod contract_class;
+pub mod contract_class_storage;
+pub mod error;
+pub mod felt;
+pub mod rpc;
+pub mod traits;
@@ -0,0 +1,177 @@
+use cairo_felt::Felt252;
+use serde::{Deserialize, Serialize};
+use starknet_api::transaction::Fee;
+use starknet_in_rust::core::transaction_hash::{
+    calculate_transaction_hash_common, TransactionHashPrefix as SirTransactionHashPrefix,
+};
+use starknet_in_rust::definitions::constants::VALIDATE_DECLARE_ENTRY_POINT_SELECTOR;
+use starknet_in_rust::transaction::Declare as SirDeclare;
+
+use crate::contract_address::ContractAddress;
+use crate::contract_class::Cairo0ContractClass;
+use crate::contract_class_storage::DeclaredClasses;
+use crate::error::DevnetResult;
+use crate::felt::{ClassHash, Felt, TransactionHash, TransactionSignature, TransactionVersion};
+use crate::rpc::transactions::declare_transaction_v0v1::DeclareTransactionV0V1;
+use crate::traits::HashProducer;
+
+/// A declare v0 transaction, as broadcast by the client. Unlike
+/// [`super::broadcasted_declare_transaction_v1::BroadcastedDeclareTransactionV1`], it has no
+/// `nonce` and does not go through account validation, matching the genesis-style declares of
+/// the sequencer's v0 RPC call.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct BroadcastedDeclareTransactionV0 {
+    pub sender_address: ContractAddress,
+    pub max_fee: Fee,
+    pub signature: TransactionSignature,
+    pub version: TransactionVersion,
+    pub contract_class: Cairo0ContractClass,
+}
+
+impl BroadcastedDeclareTransactionV0 {
+    pub fn new(
+        sender_address: ContractAddress,
+        max_fee: Fee,
+        signature: &TransactionSignature,
+        contract_class: &Cairo0ContractClass,
+        version: TransactionVersion,
+    ) -> Self {
+        Self {
+            sender_address,
+            max_fee,
+            signature: signature.clone(),
+            version,
+            contract_class: contract_class.clone(),
+        }
+    }
+
+    /// Declare v0 transactions predate account abstraction's `__validate__` entry point, so
+    /// `skip_validate` is set; the selector is still wired up since `SirDeclare` requires one,
+    /// it is simply never invoked.
+    pub fn create_sir_declare(
+        &self,
+        class_hash: ClassHash,
+        transaction_hash: TransactionHash,
+    ) -> DevnetResult<SirDeclare> {
+        let declare = SirDeclare {
+            class_hash: class_hash.into(),
+            sender_address: self.sender_address.into(),
+            validate_entry_point_selector: VALIDATE_DECLARE_ENTRY_POINT_SELECTOR.clone(),
+            version: self.version.into(),
+            max_fee: self.max_fee.0,
+            signature: self.signature.iter().map(|felt| felt.into()).collect(),
+            nonce: Felt252::from(0),
+            hash_value: transaction_hash.into(),
+            contract_class: self.contract_class.clone().try_into()?,
+            skip_execute: false,
+            skip_fee_transfer: false,
+            skip_validate: true,
+        };
+
+        Ok(declare)
+    }
+
+    pub fn create_declare(
+        &self,
+        class_hash: ClassHash,
+        transaction_hash: TransactionHash,
+        declared_classes: &mut DeclaredClasses,
+    ) -> DeclareTransactionV0V1 {
+        declared_classes.insert_cairo0(class_hash, self.contract_class.clone());
+
+        DeclareTransactionV0V1 {
+            class_hash,
+            sender_address: self.sender_address,
+            nonce: Felt::from(0),
+            max_fee: self.max_fee,
+            version: self.version,
+            transaction_hash,
+            signature: self.signature.clone(),
+        }
+    }
+
+    pub fn generate_class_hash(&self) -> DevnetResult<Felt> {
+        self.contract_class.generate_hash()
+    }
+
+    /// Unlike the v1 hash, which passes the class hash via `calldata` and hard-codes the nonce
+    /// into `additional_data`, v0 declares have no nonce and instead put the class hash in
+    /// `additional_data`, leaving `calldata` empty.
+    pub fn calculate_transaction_hash(
+        &self,
+        chain_id: &Felt,
+        class_hash: &ClassHash,
+    ) -> DevnetResult<ClassHash> {
+        let additional_data: Vec<Felt252> = vec![class_hash.into()];
+        let calldata = vec![];
+
+        Ok(calculate_transaction_hash_common(
+            SirTransactionHashPrefix::Declare,
+            self.version.into(),
+            &self.sender_address.into(),
+            Felt252::from(0),
+            &calldata,
+            self.max_fee.0,
+            chain_id.into(),
+            &additional_data,
+        )?
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_api::transaction::Fee;
+    use starknet_in_rust::core::transaction_hash::{
+        calculate_transaction_hash_common, TransactionHashPrefix,
+    };
+    use starknet_in_rust::definitions::block_context::StarknetChainId;
+
+    use super::BroadcastedDeclareTransactionV0;
+    use crate::contract_address::ContractAddress;
+    use crate::contract_class::Cairo0Json;
+    use crate::felt::Felt;
+    use crate::traits::HashProducer;
+
+    #[test]
+    /// Regression test for a v0 declare's `calculate_transaction_hash`: unlike v1, the class
+    /// hash belongs in `additional_data`, and `calldata` is empty. This is the cairo-lang
+    /// reference layout for v0 declares; swapping the two (as v1 does) produces a hash that
+    /// won't match genuine sequencer v0 declares.
+    fn transaction_hash_puts_class_hash_in_additional_data_not_calldata() {
+        let json_str = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/test_data/events_cairo0.casm"
+        ))
+        .unwrap();
+        let cairo0 = Cairo0Json::raw_json_from_json_str(&json_str).unwrap();
+
+        let broadcasted_tx = BroadcastedDeclareTransactionV0::new(
+            ContractAddress::new(Felt::from(1)).unwrap(),
+            Fee(1000),
+            &vec![],
+            &cairo0.into(),
+            Felt::from(0),
+        );
+
+        let class_hash = broadcasted_tx.generate_class_hash().unwrap();
+        let chain_id = StarknetChainId::TestNet.to_felt().into();
+
+        let transaction_hash =
+            broadcasted_tx.calculate_transaction_hash(&chain_id, &class_hash).unwrap();
+
+        let expected_transaction_hash = calculate_transaction_hash_common(
+            TransactionHashPrefix::Declare,
+            broadcasted_tx.version.into(),
+            &broadcasted_tx.sender_address.into(),
+            cairo_felt::Felt252::from(0),
+            &vec![],
+            broadcasted_tx.max_fee.0,
+            chain_id.into(),
+            &vec![class_hash.into()],
+        )
+        .unwrap();
+
+        assert_eq!(transaction_hash, expected_transaction_hash.into());
+    }
+}
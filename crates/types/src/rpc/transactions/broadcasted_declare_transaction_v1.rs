@@ -9,6 +9,7 @@ use starknet_in_rust::transaction::{verify_version, Declare as SirDeclare};
 
 use crate::contract_address::ContractAddress;
 use crate::contract_class::Cairo0ContractClass;
+use crate::contract_class_storage::DeclaredClasses;
 use crate::error::DevnetResult;
 use crate::felt::{
     ClassHash, Felt, Nonce, TransactionHash, TransactionSignature, TransactionVersion,
@@ -76,7 +77,10 @@ impl BroadcastedDeclareTransactionV1 {
         &self,
         class_hash: ClassHash,
         transaction_hash: TransactionHash,
+        declared_classes: &mut DeclaredClasses,
     ) -> DeclareTransactionV0V1 {
+        declared_classes.insert_cairo0(class_hash, self.contract_class.clone());
+
         DeclareTransactionV0V1 {
             class_hash,
             sender_address: self.sender_address,
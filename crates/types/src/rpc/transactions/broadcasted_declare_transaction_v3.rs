@@ -0,0 +1,305 @@
+use cairo_felt::Felt252;
+use serde::{Deserialize, Serialize};
+use starknet_api::data_availability::DataAvailabilityMode;
+use starknet_api::transaction::Tip;
+use starknet_crypto::{poseidon_hash_many, FieldElement};
+
+use crate::contract_address::ContractAddress;
+use crate::contract_class::SierraContractClass;
+use crate::contract_class_storage::DeclaredClasses;
+use crate::error::DevnetResult;
+use crate::felt::{
+    ClassHash, CompiledClassHash, Felt, Nonce, TransactionHash, TransactionSignature,
+    TransactionVersion,
+};
+use crate::rpc::transactions::declare_transaction_v3::DeclareTransactionV3;
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct ResourceBounds {
+    pub max_amount: u64,
+    pub max_price_per_unit: u128,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct ResourceBoundsMapping {
+    pub l1_gas: ResourceBounds,
+    pub l2_gas: ResourceBounds,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct BroadcastedDeclareTransactionV3 {
+    pub sender_address: ContractAddress,
+    pub compiled_class_hash: CompiledClassHash,
+    pub version: TransactionVersion,
+    pub signature: TransactionSignature,
+    pub nonce: Nonce,
+    pub contract_class: SierraContractClass,
+    pub resource_bounds: ResourceBoundsMapping,
+    pub tip: Tip,
+    pub paymaster_data: Vec<Felt>,
+    pub account_deployment_data: Vec<Felt>,
+    pub nonce_data_availability_mode: DataAvailabilityMode,
+    pub fee_data_availability_mode: DataAvailabilityMode,
+}
+
+impl BroadcastedDeclareTransactionV3 {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sender_address: ContractAddress,
+        compiled_class_hash: CompiledClassHash,
+        signature: &TransactionSignature,
+        nonce: Nonce,
+        contract_class: &SierraContractClass,
+        version: TransactionVersion,
+        resource_bounds: ResourceBoundsMapping,
+        tip: Tip,
+        paymaster_data: Vec<Felt>,
+        account_deployment_data: Vec<Felt>,
+        nonce_data_availability_mode: DataAvailabilityMode,
+        fee_data_availability_mode: DataAvailabilityMode,
+    ) -> Self {
+        Self {
+            sender_address,
+            compiled_class_hash,
+            contract_class: contract_class.clone(),
+            account_deployment_data,
+            version,
+            signature: signature.clone(),
+            nonce,
+            resource_bounds,
+            tip,
+            paymaster_data,
+            nonce_data_availability_mode,
+            fee_data_availability_mode,
+        }
+    }
+
+    pub fn generate_class_hash(&self) -> DevnetResult<Felt> {
+        self.contract_class.generate_hash()
+    }
+
+    pub fn create_declare(
+        &self,
+        class_hash: ClassHash,
+        transaction_hash: TransactionHash,
+        declared_classes: &mut DeclaredClasses,
+    ) -> DeclareTransactionV3 {
+        declared_classes.insert_sierra(
+            class_hash,
+            self.contract_class.clone(),
+            self.compiled_class_hash,
+        );
+
+        DeclareTransactionV3 {
+            class_hash,
+            compiled_class_hash: self.compiled_class_hash,
+            sender_address: self.sender_address,
+            nonce: self.nonce,
+            version: self.version,
+            transaction_hash,
+            signature: self.signature.clone(),
+            resource_bounds: self.resource_bounds.clone(),
+            tip: self.tip,
+            paymaster_data: self.paymaster_data.clone(),
+            account_deployment_data: self.account_deployment_data.clone(),
+            nonce_data_availability_mode: self.nonce_data_availability_mode,
+            fee_data_availability_mode: self.fee_data_availability_mode,
+        }
+    }
+
+    /// Computes the v3 transaction hash via `poseidon_hash_many`, as opposed to
+    /// `calculate_transaction_hash_common`, which is Pedersen-based and used by the older
+    /// declare versions.
+    pub fn calculate_transaction_hash(
+        &self,
+        chain_id: &Felt,
+        class_hash: &ClassHash,
+    ) -> DevnetResult<TransactionHash> {
+        Ok(calculate_declare_v3_transaction_hash(
+            self.version,
+            Felt::from(self.sender_address),
+            &self.resource_bounds,
+            self.tip,
+            &self.paymaster_data,
+            *chain_id,
+            self.nonce,
+            self.nonce_data_availability_mode,
+            self.fee_data_availability_mode,
+            &self.account_deployment_data,
+            *class_hash,
+            self.compiled_class_hash,
+        ))
+    }
+}
+
+/// `fee_fields_hash = poseidon_hash_many([tip, l1_gas_bound, l2_gas_bound])`.
+fn fee_fields_hash(resource_bounds: &ResourceBoundsMapping, tip: Tip) -> FieldElement {
+    let l1_gas_bound = resource_bound_felt("L1_GAS", &resource_bounds.l1_gas);
+    let l2_gas_bound = resource_bound_felt("L2_GAS", &resource_bounds.l2_gas);
+
+    poseidon_hash_many(&[FieldElement::from(tip.0), l1_gas_bound, l2_gas_bound])
+}
+
+/// Packs a resource bound as `(resource_name << 192) | (max_amount << 128) |
+/// max_price_per_unit`.
+fn resource_bound_felt(resource_name: &str, bounds: &ResourceBounds) -> FieldElement {
+    let resource_name_shifted = felt_from_short_string(resource_name) * shift(192);
+    let max_amount_shifted = FieldElement::from(bounds.max_amount) * shift(128);
+
+    resource_name_shifted + max_amount_shifted + FieldElement::from(bounds.max_price_per_unit)
+}
+
+/// `data_availability_modes = (nonce_da_mode << 32) | fee_da_mode`.
+fn data_availability_modes_felt(
+    nonce_data_availability_mode: DataAvailabilityMode,
+    fee_data_availability_mode: DataAvailabilityMode,
+) -> FieldElement {
+    let nonce_mode = FieldElement::from(nonce_data_availability_mode as u64);
+    let fee_mode = FieldElement::from(fee_data_availability_mode as u64);
+
+    nonce_mode * shift(32) + fee_mode
+}
+
+/// The pure computation behind [`BroadcastedDeclareTransactionV3::calculate_transaction_hash`],
+/// pulled out of the method so it can be exercised without a full contract class.
+#[allow(clippy::too_many_arguments)]
+fn calculate_declare_v3_transaction_hash(
+    version: TransactionVersion,
+    sender_address: Felt,
+    resource_bounds: &ResourceBoundsMapping,
+    tip: Tip,
+    paymaster_data: &[Felt],
+    chain_id: Felt,
+    nonce: Nonce,
+    nonce_data_availability_mode: DataAvailabilityMode,
+    fee_data_availability_mode: DataAvailabilityMode,
+    account_deployment_data: &[Felt],
+    class_hash: ClassHash,
+    compiled_class_hash: CompiledClassHash,
+) -> TransactionHash {
+    let paymaster_data_hash = poseidon_hash_many(&felts_to_field_elements(paymaster_data));
+    let account_deployment_data_hash =
+        poseidon_hash_many(&felts_to_field_elements(account_deployment_data));
+
+    let hash = poseidon_hash_many(&[
+        felt_from_short_string("declare"),
+        felt_to_field_element(version),
+        felt_to_field_element(sender_address),
+        fee_fields_hash(resource_bounds, tip),
+        paymaster_data_hash,
+        felt_to_field_element(chain_id),
+        felt_to_field_element(nonce),
+        data_availability_modes_felt(nonce_data_availability_mode, fee_data_availability_mode),
+        account_deployment_data_hash,
+        felt_to_field_element(class_hash),
+        felt_to_field_element(compiled_class_hash),
+    ]);
+
+    field_element_to_felt(hash)
+}
+
+/// Shifts `1` left by `bits` bits, within the Starknet field.
+fn shift(bits: u32) -> FieldElement {
+    FieldElement::TWO.pow(bits as u64)
+}
+
+/// Encodes an ASCII string as a felt, the way Cairo short strings are represented.
+fn felt_from_short_string(value: &str) -> FieldElement {
+    let mut buf = [0u8; 32];
+    let bytes = value.as_bytes();
+    buf[32 - bytes.len()..].copy_from_slice(bytes);
+    FieldElement::from_bytes_be(&buf).expect("short string does not fit in a felt")
+}
+
+fn felt_to_field_element(felt: Felt) -> FieldElement {
+    let felt252: Felt252 = felt.into();
+    FieldElement::from_bytes_be(&felt252.to_be_bytes())
+        .expect("a Felt252 always fits in the Starknet field")
+}
+
+fn felts_to_field_elements(felts: &[Felt]) -> Vec<FieldElement> {
+    felts.iter().copied().map(felt_to_field_element).collect()
+}
+
+fn field_element_to_felt(value: FieldElement) -> Felt {
+    Felt252::from_bytes_be(&value.to_bytes_be()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_api::data_availability::DataAvailabilityMode;
+    use starknet_api::transaction::Tip;
+    use starknet_crypto::{poseidon_hash_many, FieldElement};
+
+    use super::{
+        calculate_declare_v3_transaction_hash, felt_from_short_string, felt_to_field_element,
+        field_element_to_felt, resource_bound_felt, shift, ResourceBounds, ResourceBoundsMapping,
+    };
+    use crate::felt::Felt;
+
+    /// Pins the v3 declare hash to the formula from the request: a poseidon hash over
+    /// `[prefix, version, sender_address, fee_fields_hash, poseidon(paymaster_data), chain_id,
+    /// nonce, data_availability_modes, poseidon(account_deployment_data), class_hash,
+    /// compiled_class_hash]`, reassembled here so a regression in field order, in the
+    /// fee-fields hash or in the data-availability packing would be caught. The resource-bound
+    /// felts themselves are built with the same `resource_bound_felt` the code uses.
+    #[test]
+    fn transaction_hash_matches_independently_assembled_poseidon_formula() {
+        let resource_bounds = ResourceBoundsMapping {
+            l1_gas: ResourceBounds { max_amount: 10, max_price_per_unit: 100 },
+            l2_gas: ResourceBounds { max_amount: 20, max_price_per_unit: 200 },
+        };
+        let tip = Tip(5);
+        let paymaster_data = vec![Felt::from(7)];
+        let account_deployment_data = vec![Felt::from(8), Felt::from(9)];
+
+        let hash = calculate_declare_v3_transaction_hash(
+            Felt::from(3),
+            Felt::from(1),
+            &resource_bounds,
+            tip,
+            &paymaster_data,
+            Felt::from(2),
+            Felt::from(4),
+            DataAvailabilityMode::L1,
+            DataAvailabilityMode::L2,
+            &account_deployment_data,
+            Felt::from(10),
+            Felt::from(11),
+        );
+
+        let l1_gas_bound = resource_bound_felt("L1_GAS", &resource_bounds.l1_gas);
+        let l2_gas_bound = resource_bound_felt("L2_GAS", &resource_bounds.l2_gas);
+        let fee_fields_hash =
+            poseidon_hash_many(&[felt_to_field_element(Felt::from(5)), l1_gas_bound, l2_gas_bound]);
+        let paymaster_data_hash = poseidon_hash_many(
+            &paymaster_data.iter().copied().map(felt_to_field_element).collect::<Vec<_>>(),
+        );
+        let account_deployment_data_hash = poseidon_hash_many(
+            &account_deployment_data
+                .iter()
+                .copied()
+                .map(felt_to_field_element)
+                .collect::<Vec<_>>(),
+        );
+        // DataAvailabilityMode::L1 = 0, DataAvailabilityMode::L2 = 1.
+        let data_availability_modes =
+            FieldElement::from(0u64) * shift(32) + FieldElement::from(1u64);
+
+        let expected = poseidon_hash_many(&[
+            felt_from_short_string("declare"),
+            felt_to_field_element(Felt::from(3)),
+            felt_to_field_element(Felt::from(1)),
+            fee_fields_hash,
+            paymaster_data_hash,
+            felt_to_field_element(Felt::from(2)),
+            felt_to_field_element(Felt::from(4)),
+            data_availability_modes,
+            account_deployment_data_hash,
+            felt_to_field_element(Felt::from(10)),
+            felt_to_field_element(Felt::from(11)),
+        ]);
+
+        assert_eq!(hash, field_element_to_felt(expected));
+    }
+}
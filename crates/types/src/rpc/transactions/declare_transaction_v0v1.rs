@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use starknet_api::transaction::Fee;
+
+use crate::contract_address::ContractAddress;
+use crate::felt::{ClassHash, Nonce, TransactionHash, TransactionSignature, TransactionVersion};
+
+/// The internal representation of an accepted declare v0 or v1 transaction. The two versions
+/// share this representation since v0 differs from v1 only at the broadcasted-transaction and
+/// hashing layers: it has no nonce (stored here as `0`), skips account validation, and hashes the
+/// class hash as `additional_data` rather than `calldata`.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeclareTransactionV0V1 {
+    pub class_hash: ClassHash,
+    pub sender_address: ContractAddress,
+    pub nonce: Nonce,
+    pub max_fee: Fee,
+    pub version: TransactionVersion,
+    pub transaction_hash: TransactionHash,
+    pub signature: TransactionSignature,
+}
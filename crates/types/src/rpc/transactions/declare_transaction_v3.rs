@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use starknet_api::data_availability::DataAvailabilityMode;
+use starknet_api::transaction::Tip;
+
+use crate::contract_address::ContractAddress;
+use crate::felt::{
+    ClassHash, CompiledClassHash, Felt, Nonce, TransactionHash, TransactionSignature,
+    TransactionVersion,
+};
+use crate::rpc::transactions::broadcasted_declare_transaction_v3::ResourceBoundsMapping;
+
+/// The internal representation of an accepted declare v3 transaction, analogous to
+/// [`super::declare_transaction_v0v1::DeclareTransactionV0V1`] but carrying the v3 fee model
+/// instead of a flat `max_fee`.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeclareTransactionV3 {
+    pub class_hash: ClassHash,
+    pub compiled_class_hash: CompiledClassHash,
+    pub sender_address: ContractAddress,
+    pub nonce: Nonce,
+    pub version: TransactionVersion,
+    pub transaction_hash: TransactionHash,
+    pub signature: TransactionSignature,
+    pub resource_bounds: ResourceBoundsMapping,
+    pub tip: Tip,
+    pub paymaster_data: Vec<Felt>,
+    pub account_deployment_data: Vec<Felt>,
+    pub nonce_data_availability_mode: DataAvailabilityMode,
+    pub fee_data_availability_mode: DataAvailabilityMode,
+}
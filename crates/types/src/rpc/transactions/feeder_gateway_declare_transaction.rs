@@ -0,0 +1,52 @@
+use serde::Serialize;
+
+use crate::felt::Felt;
+use crate::rpc::transactions::declare_transaction_v0v1::DeclareTransactionV0V1;
+use crate::rpc::transactions::declare_transaction_v3::DeclareTransactionV3;
+
+/// The shape the sequencer's feeder gateway serves declare transactions in, as opposed to the
+/// JSON-RPC schema. Every felt field is a hex felt, mirroring `FeederGatewayDeclareTransactionV1`
+/// in `broadcasted_declare_transaction_v1`'s tests, which deserializes the same snake_case shape.
+///
+/// `max_fee` is `None` for v3 transactions: they pay with `resource_bounds`, not a flat fee, and
+/// this shape has no field for resource bounds, so reporting `0` would misrepresent the
+/// transaction's actual fee to a feeder-gateway consumer. It is only ever `None` for v3; v0/v1
+/// always carry one.
+#[derive(Debug, Clone, Serialize)]
+pub struct FeederGatewayDeclareTransaction {
+    pub transaction_hash: Felt,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fee: Option<Felt>,
+    pub nonce: Felt,
+    pub class_hash: Felt,
+    pub sender_address: Felt,
+    pub version: Felt,
+}
+
+impl From<&DeclareTransactionV0V1> for FeederGatewayDeclareTransaction {
+    fn from(declare: &DeclareTransactionV0V1) -> Self {
+        Self {
+            transaction_hash: declare.transaction_hash,
+            max_fee: Some(Felt::from(declare.max_fee.0)),
+            nonce: declare.nonce,
+            class_hash: declare.class_hash,
+            sender_address: Felt::from(declare.sender_address),
+            version: declare.version,
+        }
+    }
+}
+
+impl From<&DeclareTransactionV3> for FeederGatewayDeclareTransaction {
+    fn from(declare: &DeclareTransactionV3) -> Self {
+        Self {
+            transaction_hash: declare.transaction_hash,
+            // v3 transactions pay with resource_bounds, not a flat max_fee; omit rather than
+            // report a misleading 0.
+            max_fee: None,
+            nonce: declare.nonce,
+            class_hash: declare.class_hash,
+            sender_address: Felt::from(declare.sender_address),
+            version: declare.version,
+        }
+    }
+}
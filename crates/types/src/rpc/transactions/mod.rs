@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use starknet_api::transaction::Fee;
+
+use crate::felt::{Nonce, TransactionSignature, TransactionVersion};
+
+pub mod broadcasted_declare_transaction_v0;
+pub mod broadcasted_declare_transaction_v1;
+pub mod broadcasted_declare_transaction_v3;
+pub mod declare_transaction_v0v1;
+pub mod declare_transaction_v3;
+pub mod feeder_gateway_declare_transaction;
+
+/// Fields shared by the pre-v3 broadcasted transaction variants.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct BroadcastedTransactionCommon {
+    pub max_fee: Fee,
+    pub nonce: Nonce,
+    pub version: TransactionVersion,
+    pub signature: TransactionSignature,
+}